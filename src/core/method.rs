@@ -34,8 +34,26 @@ use std::fmt;
 /// assert_eq!(result.as_slice(), &[1., 1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5, 9.5]);
 /// ```
 ///
-/// # Be advised
-/// There is no `reset` method on the trait. If you need reset a state of the `Method` instance, you should just create a new one.
+/// ### Reset an instance in place
+///
+/// `reset` is opt-in: it panics unless the `Method` explicitly overrides it (see the docs on
+/// [`Method::reset`] below). [`TRIMA`](crate::methods::TRIMA) is one such implementor.
+///
+/// ```
+/// use yata::methods::TRIMA;
+/// use yata::prelude::*;
+///
+/// let s: Vec<_> = vec![1., 2., 3., 4., 5.];
+/// let mut ma = TRIMA::new(2, s[0]);
+///
+/// s.iter().for_each(|&x| {
+///     ma.next(x);
+/// });
+/// ma.reset(&s[0]);
+///
+/// let mut fresh = TRIMA::new(2, s[0]);
+/// assert_eq!(ma.next(s[0]), fresh.next(s[0]));
+/// ```
 pub trait Method: fmt::Debug {
 	/// Method parameters
 	type Params;
@@ -52,6 +70,27 @@ pub trait Method: fmt::Debug {
 	/// Generates next output value based on the given input `value`
 	fn next(&mut self, value: &Self::Input) -> Self::Output;
 
+	/// Resets the method's internal state in place, so that it becomes equivalent to a freshly
+	/// [`new`](Method::new)'d instance constructed with the same parameters and `initial_value`.
+	///
+	/// This avoids reallocating internal buffers (e.g. a [`Window`](crate::core::Window)) in
+	/// backtesting / parameter-sweep loops that rerun the same configured method over many series.
+	///
+	/// # Unsupported by default
+	///
+	/// There is no generic way to rebuild `Self` from `Self::Params` alone (the params aren't
+	/// retained by the trait), so **the default implementation always panics**. `reset` is
+	/// unsupported for any `Method` unless it explicitly opts in by overriding this method and
+	/// retaining its construction parameters; callers must check the implementor's own docs
+	/// before calling `reset` rather than assuming it works crate-wide.
+	fn reset(&mut self, initial_value: &Self::Input) {
+		let _ = initial_value;
+		unimplemented!(
+			"{} does not implement `Method::reset`; construct a new instance instead",
+			self.name()
+		)
+	}
+
 	/// Returns a name of the method
 	fn name(&self) -> &str {
 		let parts = std::any::type_name::<Self>().split("::");
@@ -99,7 +138,7 @@ pub trait Method: fmt::Debug {
 		Self::Input: Sized,
 		Self: Sized,
 	{
-		inputs.as_ref().iter().map(|x| self.next(x)).collect()
+		self.iter_over(inputs.as_ref().iter()).collect()
 	}
 
 	/// Creates new `Method` instance and iterates it over the given `inputs` slice and returns `Vec` of output values.
@@ -123,4 +162,83 @@ pub trait Method: fmt::Debug {
 
 		Ok(method.over(inputs))
 	}
+
+	/// Lazily iterates the `Method` over the given `inputs` iterator, yielding one output per
+	/// input without collecting anything into a `Vec`.
+	///
+	/// This borrows `self` for the lifetime of the returned iterator, so the instance (and its
+	/// state) is still there to use once iteration is done.
+	///
+	/// # Guarantees
+	///
+	/// The returned iterator yields exactly one output for every input, in order.
+	///
+	/// ```
+	/// use yata::methods::SMA;
+	/// use yata::prelude::*;
+	///
+	/// let s: Vec<_> = vec![1., 2., 3., 4., 5.];
+	/// let mut ma = SMA::new(2, &s[0]).unwrap();
+	///
+	/// let result: Vec<_> = ma.iter_over(s.iter()).collect();
+	/// assert_eq!(result.len(), s.len());
+	/// ```
+	#[inline]
+	fn iter_over<'a, I>(&mut self, inputs: I) -> MethodIter<'_, Self, I>
+	where
+		I: Iterator<Item = &'a Self::Input>,
+		Self::Input: 'a,
+		Self: Sized,
+	{
+		MethodIter {
+			method: self,
+			inputs,
+		}
+	}
+}
+
+/// Iterator returned by [`Method::iter_over`] and [`IteratorMethodExt::apply`].
+///
+/// Pulls one value from the wrapped input iterator at a time and feeds it straight into
+/// [`Method::next`], so no intermediate buffer is ever allocated.
+#[derive(Debug)]
+pub struct MethodIter<'m, M: ?Sized, I> {
+	method: &'m mut M,
+	inputs: I,
+}
+
+impl<'m, 'a, M, I> Iterator for MethodIter<'m, M, I>
+where
+	M: Method + ?Sized,
+	M::Input: 'a,
+	I: Iterator<Item = &'a M::Input>,
+{
+	type Item = M::Output;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inputs.next().map(|x| self.method.next(x))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inputs.size_hint()
+	}
 }
+
+/// Extension trait over [`Iterator`] that allows applying a [`Method`] directly in an iterator
+/// chain, e.g. `prices.iter().apply(&mut sma)`.
+pub trait IteratorMethodExt<'a, T: 'a>: Iterator<Item = &'a T> + Sized {
+	/// Feeds this iterator through `method`, yielding one output per input.
+	///
+	/// See [`Method::iter_over`].
+	#[inline]
+	fn apply<M>(self, method: &mut M) -> MethodIter<'_, M, Self>
+	where
+		M: Method<Input = T>,
+	{
+		method.iter_over(self)
+	}
+}
+
+impl<'a, T: 'a, I> IteratorMethodExt<'a, T> for I where I: Iterator<Item = &'a T> {}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
-use crate::methods::{Change, CrossAbove, CrossUnder};
+use crate::methods::{Change, CrossAbove, CrossUnder, RegularMethodInstance, RegularMethods};
 
 /// Chande Momentum Oscillator
 ///
@@ -11,17 +11,24 @@ use crate::methods::{Change, CrossAbove, CrossUnder};
 ///
 /// * <https://www.investopedia.com/terms/c/chandemomentumoscillator.asp>
 ///
-/// # 1 value
+/// # 2 values
 ///
 /// * `oscillator` value
 ///
 /// Range in \[`-1.0`; `1.0`\]
 ///
-/// # 1 signal
+/// * `signal line` value
 ///
-/// When `oscillator` value goes above `zone`, then returns full sell signal.
-/// When `oscillator` value goes below `-zone`, then returns full buy signal.
-/// Otherwise no signal
+/// Range in \[`-1.0`; `1.0`\]. Only produced when `signal_period` is not `0`, otherwise always `0.0`.
+///
+/// # 2 signals
+///
+/// * When `oscillator` value goes above `zone`, then returns full sell signal.
+///   When `oscillator` value goes below `-zone`, then returns full buy signal.
+///   Otherwise no signal.
+///
+/// * When `signal_period` is not `0`: when `oscillator` crosses `signal line` from below, returns
+///   full buy signal; when it crosses from above, returns full sell signal. Otherwise no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChandeMomentumOscillator {
@@ -33,6 +40,14 @@ pub struct ChandeMomentumOscillator {
 	///
 	/// Range in \[`0.0`; `1.0`\]
 	pub zone: ValueType,
+	/// Signal line period. Default is `0` (signal line disabled).
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\)
+	pub signal_period: PeriodType,
+	/// Signal line moving average method. Default is [`TRIMA`](crate::methods::RegularMethods::TRIMA).
+	///
+	/// Only used when `signal_period` is not `0`.
+	pub signal_method: RegularMethods,
 	/// Source type. Default is [`Close`](crate::core::Source::Close)
 	pub source: Source,
 }
@@ -49,6 +64,12 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 
 		let cfg = self;
 
+		let signal = if cfg.signal_period != 0 {
+			Some(cfg.signal_method.init(cfg.signal_period, 0.)?)
+		} else {
+			None
+		};
+
 		Ok(Self::Instance {
 			pos_sum: 0.,
 			neg_sum: 0.,
@@ -56,6 +77,9 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 			window: Window::new(cfg.period, 0.),
 			cross_under: CrossUnder::default(),
 			cross_above: CrossAbove::default(),
+			signal,
+			signal_cross_above: CrossAbove::default(),
+			signal_cross_under: CrossUnder::default(),
 			cfg,
 		})
 	}
@@ -74,6 +98,14 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
 			},
+			"signal_period" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_period = value,
+			},
+			"signal_method" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.signal_method = value,
+			},
 			"source" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
@@ -88,7 +120,7 @@ impl IndicatorConfig for ChandeMomentumOscillator {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 1)
+		(2, 2)
 	}
 }
 
@@ -97,6 +129,8 @@ impl Default for ChandeMomentumOscillator {
 		Self {
 			period: 9,
 			zone: 0.5,
+			signal_period: 0,
+			signal_method: RegularMethods::TRIMA,
 			source: Source::Close,
 		}
 	}
@@ -112,6 +146,9 @@ pub struct ChandeMomentumOscillatorInstance {
 	window: Window<ValueType>,
 	cross_under: CrossUnder,
 	cross_above: CrossAbove,
+	signal: Option<RegularMethodInstance>,
+	signal_cross_above: CrossAbove,
+	signal_cross_under: CrossUnder,
 }
 
 #[inline]
@@ -124,6 +161,24 @@ fn change(change: ValueType) -> (ValueType, ValueType) {
 	(pos, neg)
 }
 
+impl ChandeMomentumOscillatorInstance {
+	/// Advances the optional signal line with the latest oscillator `value` and derives a
+	/// crossover signal from it. Returns `(0., 0)` when no signal line is configured.
+	fn signal_step(&mut self, value: ValueType) -> (ValueType, i8) {
+		match &mut self.signal {
+			Some(signal_ma) => {
+				let signal_value = signal_ma.next(&value);
+
+				let cross_signal = self.signal_cross_above.next(&(value, signal_value))
+					- self.signal_cross_under.next(&(value, signal_value));
+
+				(signal_value, cross_signal)
+			}
+			None => (0., 0),
+		}
+	}
+}
+
 impl IndicatorInstance for ChandeMomentumOscillatorInstance {
 	type Config = ChandeMomentumOscillator;
 
@@ -150,6 +205,83 @@ impl IndicatorInstance for ChandeMomentumOscillatorInstance {
 		let signal = self.cross_under.next(&(value, -self.cfg.zone))
 			- self.cross_above.next(&(value, self.cfg.zone));
 
-		IndicatorResult::new(&[value], &[signal])
+		let (signal_value, signal_line_signal) = self.signal_step(value);
+
+		IndicatorResult::new(&[value, signal_value], &[signal, signal_line_signal])
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn test_instance(signal_period: PeriodType) -> ChandeMomentumOscillatorInstance {
+		let cfg = ChandeMomentumOscillator {
+			signal_period,
+			signal_method: RegularMethods::TRIMA,
+			..ChandeMomentumOscillator::default()
+		};
+
+		let signal = if signal_period != 0 {
+			Some(cfg.signal_method.init(signal_period, 0.).unwrap())
+		} else {
+			None
+		};
+
+		ChandeMomentumOscillatorInstance {
+			pos_sum: 0.,
+			neg_sum: 0.,
+			change: Change::new(1, &0.).unwrap(),
+			window: Window::new(cfg.period, 0.),
+			cross_under: CrossUnder::default(),
+			cross_above: CrossAbove::default(),
+			signal,
+			signal_cross_above: CrossAbove::default(),
+			signal_cross_under: CrossUnder::default(),
+			cfg,
+		}
+	}
+
+	#[test]
+	fn test_signal_disabled_by_default() {
+		assert_eq!(ChandeMomentumOscillator::default().signal_period, 0);
+
+		let mut instance = test_instance(0);
+
+		[0.1, -0.3, 0.5, -0.7, 0.].iter().for_each(|&value| {
+			assert_eq!(instance.signal_step(value), (0., 0));
+		});
+	}
+
+	#[test]
+	fn test_signal_crossover_sign() {
+		let mut instance = test_instance(2);
+
+		// Oscillator rises, then falls back: the (lagging) signal line should get crossed from
+		// below on the way up (buy) and from above on the way back down (sell).
+		let values = [0.1, 0.3, 0.5, 0.7, 0.5, 0.3, 0.1, -0.1];
+
+		let mut saw_buy = false;
+		let mut saw_sell = false;
+
+		values.iter().for_each(|&value| {
+			let (_, cross_signal) = instance.signal_step(value);
+
+			if cross_signal > 0 {
+				saw_buy = true;
+			}
+			if cross_signal < 0 {
+				saw_sell = true;
+			}
+		});
+
+		assert!(
+			saw_buy,
+			"expected a buy crossover while the oscillator was rising"
+		);
+		assert!(
+			saw_sell,
+			"expected a sell crossover while the oscillator was falling back"
+		);
 	}
 }
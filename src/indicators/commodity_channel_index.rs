@@ -1,7 +1,7 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, OHLCV};
+use crate::core::{Action, Error, Method, PeriodType, Source, ValueType, Window, OHLCV};
 use crate::core::{IndicatorConfig, IndicatorInstance, IndicatorResult};
 use crate::methods::CCI;
 
@@ -18,11 +18,17 @@ const SCALE: ValueType = 1.0 / 1.5;
 ///
 /// Range in \(`-inf`; `+inf`\)
 ///
-/// # 1 signal
+/// # 2 signals
 ///
-/// When `oscillator` value goes above `zone`, then returns full sell signal.
-/// When `oscillator` value goes below `-zone`, then returns full buy signal.
-/// Otherwise no signal
+/// * When `oscillator` value goes above `zone`, then returns full sell signal.
+///   When `oscillator` value goes below `-zone`, then returns full buy signal.
+///   Otherwise no signal.
+///
+/// * When `divergence_lookback` is not `0`: when price makes a higher high while `oscillator`
+///   makes a lower high, returns a sell signal (bearish divergence). When price makes a lower low
+///   while `oscillator` makes a higher low, returns a buy signal (bullish divergence). Signal
+///   strength is proportional to the difference between the two `oscillator` pivot values
+///   (the price difference is not used), capped at full strength. Otherwise no signal.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CommodityChannelIndex {
@@ -36,6 +42,12 @@ pub struct CommodityChannelIndex {
 	/// Range in \[`0.0`; `+inf`\).
 	pub zone: ValueType,
 
+	/// Swing lookback length used for price/oscillator divergence detection. Default is `0`
+	/// (divergence detection disabled).
+	///
+	/// Range in \[`0`; [`PeriodType::MAX`](crate::core::PeriodType)\).
+	pub divergence_lookback: PeriodType,
+
 	/// Source type. Default is [`Close`](crate::core::Source::Close).
 	pub source: Source,
 }
@@ -53,10 +65,22 @@ impl IndicatorConfig for CommodityChannelIndex {
 		let cfg = self;
 		let value = candle.source(cfg.source);
 
+		let divergence = if cfg.divergence_lookback != 0 {
+			Some(Divergence {
+				price_window: Window::new(cfg.divergence_lookback, value),
+				cci_window: Window::new(cfg.divergence_lookback, 0.),
+				last_pivot_high: None,
+				last_pivot_low: None,
+			})
+		} else {
+			None
+		};
+
 		Ok(Self::Instance {
 			last_cci: 0.,
 			last_signal: 0,
 			cci: CCI::new(cfg.period, &value)?,
+			divergence,
 
 			cfg,
 		})
@@ -76,6 +100,10 @@ impl IndicatorConfig for CommodityChannelIndex {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.zone = value,
 			},
+			"divergence_lookback" => match value.parse() {
+				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
+				Ok(value) => self.divergence_lookback = value,
+			},
 			"source" => match value.parse() {
 				Err(_) => return Err(Error::ParameterParse(name.to_string(), value.to_string())),
 				Ok(value) => self.source = value,
@@ -90,7 +118,7 @@ impl IndicatorConfig for CommodityChannelIndex {
 	}
 
 	fn size(&self) -> (u8, u8) {
-		(1, 1)
+		(1, 2)
 	}
 }
 
@@ -99,11 +127,69 @@ impl Default for CommodityChannelIndex {
 		Self {
 			period: 18,
 			zone: 1.0,
+			divergence_lookback: 0,
 			source: Source::Close,
 		}
 	}
 }
 
+/// Rolling swing-high/swing-low tracker used for price/oscillator divergence detection.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Divergence {
+	price_window: Window<ValueType>,
+	cci_window: Window<ValueType>,
+	last_pivot_high: Option<(ValueType, ValueType)>,
+	last_pivot_low: Option<(ValueType, ValueType)>,
+}
+
+impl Divergence {
+	/// Pushes the latest `price`/`cci` pair and returns a divergence signal, if any.
+	fn next(&mut self, price: ValueType, cci: ValueType) -> i8 {
+		self.price_window.push(price);
+		self.cci_window.push(cci);
+
+		let is_swing_high = self.price_window.iter().all(|&x| x <= price);
+		let is_swing_low = self.price_window.iter().all(|&x| x >= price);
+
+		let mut bearish_signal = 0;
+		let mut bullish_signal = 0;
+
+		if is_swing_high {
+			if let Some((last_price, last_cci)) = self.last_pivot_high {
+				if price > last_price && cci < last_cci {
+					bearish_signal = -strength(last_cci - cci);
+				}
+			}
+			self.last_pivot_high = Some((price, cci));
+		}
+
+		if is_swing_low {
+			if let Some((last_price, last_cci)) = self.last_pivot_low {
+				if price < last_price && cci > last_cci {
+					bullish_signal = strength(cci - last_cci);
+				}
+			}
+			self.last_pivot_low = Some((price, cci));
+		}
+
+		// A short `divergence_lookback` (or a flat run of equal prices) can make `price` both a
+		// swing high and a swing low on the same bar, firing both branches above. Rather than
+		// letting one silently clobber the other, keep whichever signal is stronger.
+		if bearish_signal.abs() >= bullish_signal.abs() {
+			bearish_signal
+		} else {
+			bullish_signal
+		}
+	}
+}
+
+/// Scales a pivot magnitude difference into a signal strength in range `[1; 100]`.
+#[inline]
+fn strength(diff: ValueType) -> i8 {
+	(diff.abs() * 100.).max(1.).min(100.) as i8
+}
+
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CommodityChannelIndexInstance {
@@ -112,6 +198,7 @@ pub struct CommodityChannelIndexInstance {
 	cci: CCI,
 	last_cci: ValueType,
 	last_signal: i8,
+	divergence: Option<Divergence>,
 }
 
 impl IndicatorInstance for CommodityChannelIndexInstance {
@@ -149,6 +236,63 @@ impl IndicatorInstance for CommodityChannelIndexInstance {
 		self.last_cci = cci;
 		self.last_signal = signal;
 
-		IndicatorResult::new(&[cci], &[Action::from(signal)])
+		let divergence_signal = self
+			.divergence
+			.as_mut()
+			.map_or(0, |divergence| divergence.next(value, cci));
+
+		IndicatorResult::new(
+			&[cci],
+			&[Action::from(signal), Action::from(divergence_signal)],
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_divergence_bearish_signal() {
+		let mut divergence = Divergence {
+			price_window: Window::new(3, 1.),
+			cci_window: Window::new(3, 1.),
+			last_pivot_high: Some((1., 2.)),
+			last_pivot_low: None,
+		};
+
+		// Price makes a new high (window becomes [1, 1, 2]) while the oscillator makes a lower
+		// high (2 -> 1): a bearish divergence, full sell strength.
+		assert_eq!(divergence.next(2., 1.), -100);
+	}
+
+	#[test]
+	fn test_divergence_bullish_signal() {
+		let mut divergence = Divergence {
+			price_window: Window::new(3, 5.),
+			cci_window: Window::new(3, 5.),
+			last_pivot_high: None,
+			last_pivot_low: Some((5., 1.)),
+		};
+
+		// Price makes a new low (window becomes [5, 5, 2]) while the oscillator makes a higher
+		// low (1 -> 3): a bullish divergence, full buy strength.
+		assert_eq!(divergence.next(2., 3.), 100);
+	}
+
+	#[test]
+	fn test_divergence_tie_break_keeps_stronger_signal() {
+		// With a flat bar every value in the window equals `price`, so `price` is trivially both
+		// a swing high and a swing low on the same bar.
+		let mut divergence = Divergence {
+			price_window: Window::new(2, 8.),
+			cci_window: Window::new(2, 5.),
+			last_pivot_high: Some((5., 10.)), // bearish: 8 > 5 && 5 < 10 -> strength 100
+			last_pivot_low: Some((20., 1.)),  // bullish: 8 < 20 && 5 > 1 -> strength 100
+		};
+
+		// Both branches fire with equal magnitude; the bearish signal must win the tie rather
+		// than being silently overwritten by the bullish one.
+		assert_eq!(divergence.next(8., 5.), -100);
 	}
 }
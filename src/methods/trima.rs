@@ -47,6 +47,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TRIMA {
+	length: PeriodType,
 	sma1: SMA,
 	sma2: SMA,
 }
@@ -60,6 +61,7 @@ impl Method for TRIMA {
 		debug_assert!(length > 0, "TRIMA: length should be > 0");
 
 		Self {
+			length,
 			sma1: SMA::new(length, value),
 			sma2: SMA::new(length, value),
 		}
@@ -69,6 +71,12 @@ impl Method for TRIMA {
 	fn next(&mut self, value: Self::Input) -> Self::Output {
 		self.sma2.next(self.sma1.next(value))
 	}
+
+	/// Resets `TRIMA` in place, leaving it equivalent to `TRIMA::new(length, initial_value)`.
+	fn reset(&mut self, initial_value: &Self::Input) {
+		self.sma1 = SMA::new(self.length, *initial_value);
+		self.sma2 = SMA::new(self.length, *initial_value);
+	}
 }
 
 #[cfg(test)]
@@ -107,6 +115,25 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn test_trima_reset() {
+		let candles = RandomCandles::default();
+
+		let src: Vec<ValueType> = candles.take(100).map(|x| x.close).collect();
+
+		(1..20).for_each(|sma_length| {
+			let mut ma = TestingMethod::new(sma_length, src[0]);
+			ma.over(&src);
+			ma.reset(&src[0]);
+
+			let mut fresh = TestingMethod::new(sma_length, src[0]);
+
+			src.iter().for_each(|&x| {
+				assert_eq!(ma.next(x), fresh.next(x));
+			});
+		});
+	}
+
 	#[test]
 	fn test_trima() {
 		let candles = RandomCandles::default();